@@ -5,12 +5,22 @@ import (
 	"fyne.io/fyne/v2/canvas"
 	"fyne.io/fyne/v2/container"
 	"fyne.io/fyne/v2/dialog"
+	"fyne.io/fyne/v2/driver/desktop"
 	"fyne.io/fyne/v2/widget"
 	"image/color"
+	"runtime"
+	"strings"
 )
 
+// prefEnterSends is the preference key for sending messages with Enter
+const prefEnterSends = "enterSends"
+
+// maxInputHistory bounds how many sent messages the composer remembers
+const maxInputHistory = 50
+
 // MainUI holds the main application UI state
 type MainUI struct {
+	app         fyne.App
 	window      *fyne.Window
 	toggleTheme func()
 	isDarkMode  bool
@@ -19,8 +29,9 @@ type MainUI struct {
 }
 
 // NewMainUI creates a new MainUI instance
-func NewMainUI(window *fyne.Window, toggleTheme func(), isDark bool, font fyne.Resource) *MainUI {
+func NewMainUI(app fyne.App, window *fyne.Window, toggleTheme func(), isDark bool, font fyne.Resource) *MainUI {
 	return &MainUI{
+		app:         app,
 		window:     window, 
 		toggleTheme: toggleTheme,
 		isDarkMode:  isDark,
@@ -74,21 +85,26 @@ func (m *MainUI) BuildUI() fyne.CanvasObject {
 	chatArea := widget.NewRichTextFromMarkdown("**Chat messages will appear here...**")
 	chatArea.Wrapping = fyne.TextWrapWord
 	
-	messageEntry := widget.NewEntry()
-	messageEntry.SetPlaceHolder("Type your message here...")
+	prefs := m.app.Preferences()
+	enterSends := prefs.BoolWithFallback(prefEnterSends, true)
 	
-	sendBtn := widget.NewButton("Send", func() {
+	var messageEntry *chatInput
+	sendMessage := func() {
 		m.handleSend(statusLabel, messageEntry.Text)
 		messageEntry.SetText("")
-	})
+	}
+	messageEntry = newChatInput(m.app.Driver(), enterSends, sendMessage)
+	
+	sendBtn := widget.NewButton("Send", messageEntry.send)
 	sendBtn.Importance = widget.HighImportance
 	
-	messageContainer := container.NewMax(messageEntry)
-	messageContainer.Resize(fyne.NewSize(900, 60))
-	sendContainer := container.NewMax(sendBtn)
-	sendContainer.Resize(fyne.NewSize(120, 60))
+	enterSendsCheck := widget.NewCheck("Enter to send", func(on bool) {
+		prefs.SetBool(prefEnterSends, on)
+		messageEntry.setEnterSends(on)
+	})
+	enterSendsCheck.Checked = enterSends
 	
-	inputRow := container.NewHBox(messageContainer, sendContainer)
+	inputRow := container.NewBorder(nil, nil, nil, container.NewHBox(enterSendsCheck, sendBtn), messageEntry)
 
 	chatSection := container.NewBorder(nil, inputRow, nil, nil, container.NewScroll(chatArea))
 
@@ -107,11 +123,149 @@ func (m *MainUI) handleConnect(jid, password string) {
 }
 
 func (m *MainUI) handleSend(status *widget.Label, message string) {
-	if message != "" {
+	if !isBlank(message) {
 		status.SetText("Status: Message sent!")
 	}
 }
 
+// chatInput is a multi-line message entry that sends on Enter or Ctrl/Cmd+Enter
+type chatInput struct {
+	widget.Entry
+	driver     fyne.Driver
+	enterSends bool
+	onSend     func()
+	history    []string
+	historyPos int
+}
+
+func newChatInput(driver fyne.Driver, enterSends bool, onSend func()) *chatInput {
+	c := &chatInput{driver: driver, onSend: onSend}
+	c.MultiLine = true
+	c.Wrapping = fyne.TextWrapWord
+	c.ExtendBaseWidget(c)
+
+	c.setEnterSends(enterSends)
+	return c
+}
+
+// setEnterSends switches between Enter and Ctrl/Cmd+Enter for sending
+func (c *chatInput) setEnterSends(enterSends bool) {
+	c.enterSends = enterSends
+	if enterSends {
+		c.SetPlaceHolder("Type your message here... (Enter to send, Shift+Enter for new line)")
+	} else {
+		_, name := primaryModifier()
+		c.SetPlaceHolder("Type your message here... (" + name + "+Enter to send)")
+	}
+}
+
+// AcceptsTab keeps Tab moving focus even though the entry is multi-line
+func (c *chatInput) AcceptsTab() bool {
+	return false
+}
+
+func (c *chatInput) TypedKey(key *fyne.KeyEvent) {
+	shift := c.shiftHeld()
+	if c.enterSends && !shift && isReturnKey(key.Name) {
+		c.send()
+		return
+	}
+	switch {
+	case shift:
+		// Shift+Up/Down extend the selection, so leave them to the entry
+	case key.Name == fyne.KeyUp && c.Text == "":
+		// An empty input always starts again from the newest message
+		c.historyPos = len(c.history)
+		c.recallPrevious()
+		return
+	case key.Name == fyne.KeyUp && c.recalling() && c.CursorRow == 0:
+		c.recallPrevious()
+		return
+	case key.Name == fyne.KeyDown && c.recalling():
+		// Move down within the message first; only a Down on the last
+		// row steps forward in history
+		row := c.CursorRow
+		c.Entry.TypedKey(key)
+		if c.CursorRow != row {
+			return
+		}
+		c.historyPos++
+		if c.historyPos < len(c.history) {
+			c.showText(c.history[c.historyPos])
+		} else {
+			c.showText("")
+		}
+		return
+	}
+	c.Entry.TypedKey(key)
+}
+
+// TypedShortcut sends on Ctrl+Enter (Cmd+Enter on macOS) regardless of
+// the Enter setting
+func (c *chatInput) TypedShortcut(shortcut fyne.Shortcut) {
+	modifier, _ := primaryModifier()
+	if s, ok := shortcut.(*desktop.CustomShortcut); ok && s.Modifier == modifier && isReturnKey(s.KeyName) {
+		c.send()
+		return
+	}
+	c.Entry.TypedShortcut(shortcut)
+}
+
+func (c *chatInput) send() {
+	if !isBlank(c.Text) && (len(c.history) == 0 || c.history[len(c.history)-1] != c.Text) {
+		c.history = append(c.history, c.Text)
+		if len(c.history) > maxInputHistory {
+			c.history = c.history[len(c.history)-maxInputHistory:]
+		}
+	}
+	c.historyPos = len(c.history)
+	if c.onSend != nil {
+		c.onSend()
+	}
+}
+
+// recallPrevious shows the history entry before historyPos, if any
+func (c *chatInput) recallPrevious() {
+	if c.historyPos > 0 {
+		c.historyPos--
+		c.showText(c.history[c.historyPos])
+	}
+}
+
+// recalling reports whether the entry still shows an unedited history item
+func (c *chatInput) recalling() bool {
+	return c.historyPos < len(c.history) && c.Text == c.history[c.historyPos]
+}
+
+// showText replaces the text and moves the cursor to its end
+func (c *chatInput) showText(text string) {
+	c.SetText(text)
+	c.Entry.TypedKey(&fyne.KeyEvent{Name: fyne.KeyPageDown})
+}
+
+// isBlank reports whether a message has nothing to send
+func isBlank(message string) bool {
+	return strings.TrimSpace(message) == ""
+}
+
+// shiftHeld reports whether Shift is currently pressed on desktop drivers
+func (c *chatInput) shiftHeld() bool {
+	d, ok := c.driver.(desktop.Driver)
+	return ok && d.CurrentKeyModifiers()&fyne.KeyModifierShift != 0
+}
+
+// primaryModifier returns the platform's send modifier and its label
+func primaryModifier() (fyne.KeyModifier, string) {
+	if runtime.GOOS == "darwin" {
+		return fyne.KeyModifierSuper, "Cmd"
+	}
+	return fyne.KeyModifierControl, "Ctrl"
+}
+
+func isReturnKey(name fyne.KeyName) bool {
+	return name == fyne.KeyReturn || name == fyne.KeyEnter
+}
+
 // FIXED: Proper canvas toggle widget
 type toggleSwitch struct {
 	widget.BaseWidget
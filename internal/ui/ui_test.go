@@ -0,0 +1,272 @@
+package ui
+
+import (
+	"fmt"
+	"testing"
+
+	"fyne.io/fyne/v2"
+	"fyne.io/fyne/v2/driver/desktop"
+	"fyne.io/fyne/v2/test"
+)
+
+// modifierDriver reports fixed key modifiers like a desktop driver
+type modifierDriver struct {
+	fyne.Driver
+	modifiers fyne.KeyModifier
+}
+
+func (d *modifierDriver) CreateSplashWindow() fyne.Window {
+	return d.CreateWindow("")
+}
+
+func (d *modifierDriver) CurrentKeyModifiers() fyne.KeyModifier {
+	return d.modifiers
+}
+
+// newTestChatInput returns an input, its driver and a count of sends
+func newTestChatInput(t *testing.T, enterSends bool) (*chatInput, *modifierDriver, *int) {
+	a := test.NewApp()
+	t.Cleanup(a.Quit)
+
+	d := &modifierDriver{Driver: a.Driver()}
+	sent := 0
+	var c *chatInput
+	c = newChatInput(d, enterSends, func() {
+		sent++
+		c.SetText("")
+	})
+	w := test.NewWindow(c)
+	w.Resize(fyne.NewSize(400, 200))
+	t.Cleanup(w.Close)
+	return c, d, &sent
+}
+
+func typeKey(c *chatInput, name fyne.KeyName) {
+	c.TypedKey(&fyne.KeyEvent{Name: name})
+}
+
+func typeSendShortcut(c *chatInput) {
+	modifier, _ := primaryModifier()
+	c.TypedShortcut(&desktop.CustomShortcut{KeyName: fyne.KeyReturn, Modifier: modifier})
+}
+
+// setTextAtEnd replaces the text and moves the cursor after it
+func setTextAtEnd(c *chatInput, text string) {
+	c.SetText(text)
+	c.Entry.TypedKey(&fyne.KeyEvent{Name: fyne.KeyPageDown})
+}
+
+func TestChatInputEnterSends(t *testing.T) {
+	c, _, sent := newTestChatInput(t, true)
+	setTextAtEnd(c, "hello")
+	typeKey(c, fyne.KeyReturn)
+
+	if *sent != 1 || c.Text != "" {
+		t.Errorf("sent = %d, Text = %q, want 1 send and empty text", *sent, c.Text)
+	}
+}
+
+func TestChatInputShiftEnterAddsNewline(t *testing.T) {
+	c, d, sent := newTestChatInput(t, true)
+	d.modifiers = fyne.KeyModifierShift
+	setTextAtEnd(c, "hello")
+	typeKey(c, fyne.KeyReturn)
+
+	if *sent != 0 || c.Text != "hello\n" {
+		t.Errorf("sent = %d, Text = %q, want no send and %q", *sent, c.Text, "hello\n")
+	}
+}
+
+func TestChatInputEnterAddsNewlineWhenDisabled(t *testing.T) {
+	c, _, sent := newTestChatInput(t, false)
+	setTextAtEnd(c, "hello")
+	typeKey(c, fyne.KeyReturn)
+
+	if *sent != 0 || c.Text != "hello\n" {
+		t.Errorf("sent = %d, Text = %q, want no send and %q", *sent, c.Text, "hello\n")
+	}
+}
+
+func TestChatInputShortcutSendsInBothModes(t *testing.T) {
+	for _, enterSends := range []bool{true, false} {
+		c, _, sent := newTestChatInput(t, enterSends)
+		setTextAtEnd(c, "hello")
+		typeSendShortcut(c)
+
+		if *sent != 1 || c.Text != "" {
+			t.Errorf("enterSends = %v: sent = %d, Text = %q, want 1 send and empty text", enterSends, *sent, c.Text)
+		}
+	}
+}
+
+func TestChatInputPlaceHolderFollowsSetting(t *testing.T) {
+	c, _, _ := newTestChatInput(t, true)
+	if want := "Type your message here... (Enter to send, Shift+Enter for new line)"; c.PlaceHolder != want {
+		t.Errorf("PlaceHolder = %q, want %q", c.PlaceHolder, want)
+	}
+
+	c.setEnterSends(false)
+	_, name := primaryModifier()
+	if want := "Type your message here... (" + name + "+Enter to send)"; c.PlaceHolder != want {
+		t.Errorf("PlaceHolder = %q, want %q", c.PlaceHolder, want)
+	}
+}
+
+func sendText(c *chatInput, text string) {
+	c.SetText(text)
+	typeKey(c, fyne.KeyReturn)
+}
+
+func assertState(t *testing.T, c *chatInput, text string, pos int) {
+	t.Helper()
+	if c.Text != text {
+		t.Errorf("Text = %q, want %q", c.Text, text)
+	}
+	if c.historyPos != pos {
+		t.Errorf("historyPos = %d, want %d", c.historyPos, pos)
+	}
+}
+
+func TestChatInputHistoryLimit(t *testing.T) {
+	c, _, _ := newTestChatInput(t, true)
+	for i := 0; i < maxInputHistory+10; i++ {
+		sendText(c, fmt.Sprintf("message %d", i))
+	}
+
+	if len(c.history) != maxInputHistory {
+		t.Fatalf("len(history) = %d, want %d", len(c.history), maxInputHistory)
+	}
+	if c.history[0] != "message 10" {
+		t.Errorf("oldest entry = %q, want %q", c.history[0], "message 10")
+	}
+	assertState(t, c, "", maxInputHistory)
+}
+
+func TestChatInputUpRecallsWhenEmptyOrUnedited(t *testing.T) {
+	c, _, _ := newTestChatInput(t, true)
+	sendText(c, "one")
+	sendText(c, "two")
+
+	typeKey(c, fyne.KeyUp)
+	assertState(t, c, "two", 1)
+	typeKey(c, fyne.KeyUp)
+	assertState(t, c, "one", 0)
+	typeKey(c, fyne.KeyUp)
+	assertState(t, c, "one", 0)
+}
+
+func TestChatInputEditEndsRecall(t *testing.T) {
+	c, _, _ := newTestChatInput(t, true)
+	sendText(c, "one")
+	sendText(c, "two")
+
+	typeKey(c, fyne.KeyUp)
+	assertState(t, c, "two", 1)
+	setTextAtEnd(c, "two!")
+	typeKey(c, fyne.KeyUp)
+	assertState(t, c, "two!", 1)
+}
+
+func TestChatInputUpIgnoresDraft(t *testing.T) {
+	c, _, _ := newTestChatInput(t, true)
+	sendText(c, "one")
+
+	c.SetText("draft")
+	typeKey(c, fyne.KeyUp)
+	assertState(t, c, "draft", 1)
+}
+
+func TestChatInputUpAfterClearingStartsFromNewest(t *testing.T) {
+	c, _, _ := newTestChatInput(t, true)
+	sendText(c, "one")
+	sendText(c, "two")
+
+	typeKey(c, fyne.KeyUp)
+	assertState(t, c, "two", 1)
+	c.SetText("")
+	typeKey(c, fyne.KeyUp)
+	assertState(t, c, "two", 1)
+}
+
+func TestChatInputShiftUpDoesNotRecall(t *testing.T) {
+	c, d, _ := newTestChatInput(t, true)
+	sendText(c, "one")
+
+	d.modifiers = fyne.KeyModifierShift
+	typeKey(c, fyne.KeyUp)
+	assertState(t, c, "", 1)
+}
+
+func TestChatInputDownClearsAtEnd(t *testing.T) {
+	c, _, _ := newTestChatInput(t, true)
+	sendText(c, "one")
+	sendText(c, "two")
+
+	typeKey(c, fyne.KeyUp)
+	typeKey(c, fyne.KeyUp)
+	typeKey(c, fyne.KeyDown)
+	assertState(t, c, "two", 1)
+	typeKey(c, fyne.KeyDown)
+	assertState(t, c, "", 2)
+}
+
+func TestChatInputSendResetsHistoryPos(t *testing.T) {
+	c, _, _ := newTestChatInput(t, true)
+	sendText(c, "one")
+	sendText(c, "two")
+
+	typeKey(c, fyne.KeyUp)
+	typeKey(c, fyne.KeyUp)
+	typeSendShortcut(c)
+	assertState(t, c, "", 3)
+	if c.history[2] != "one" {
+		t.Errorf("newest entry = %q, want %q", c.history[2], "one")
+	}
+}
+
+func TestChatInputSkipsBlankAndRepeatedMessages(t *testing.T) {
+	c, _, _ := newTestChatInput(t, true)
+	sendText(c, "one")
+	sendText(c, "  \n")
+	sendText(c, "one")
+
+	if len(c.history) != 1 {
+		t.Fatalf("history = %q, want one entry", c.history)
+	}
+	assertState(t, c, "", 1)
+}
+
+func TestChatInputMultiLineRecall(t *testing.T) {
+	c, _, _ := newTestChatInput(t, true)
+	sendText(c, "first")
+	c.SetText("line one\nline two\nline three")
+	c.send()
+
+	typeKey(c, fyne.KeyUp)
+	assertState(t, c, "line one\nline two\nline three", 1)
+	if c.CursorRow != 2 {
+		t.Fatalf("CursorRow = %d, want 2", c.CursorRow)
+	}
+
+	// Up and Down move between lines before switching entries
+	typeKey(c, fyne.KeyUp)
+	typeKey(c, fyne.KeyUp)
+	assertState(t, c, "line one\nline two\nline three", 1)
+	if c.CursorRow != 0 {
+		t.Fatalf("CursorRow = %d, want 0", c.CursorRow)
+	}
+	typeKey(c, fyne.KeyDown)
+	assertState(t, c, "line one\nline two\nline three", 1)
+	if c.CursorRow != 1 {
+		t.Fatalf("CursorRow = %d, want 1", c.CursorRow)
+	}
+
+	typeKey(c, fyne.KeyUp)
+	typeKey(c, fyne.KeyUp)
+	assertState(t, c, "first", 0)
+
+	typeKey(c, fyne.KeyDown)
+	assertState(t, c, "line one\nline two\nline three", 1)
+	typeKey(c, fyne.KeyDown)
+	assertState(t, c, "", 2)
+}
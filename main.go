@@ -30,7 +30,7 @@ func main() {
 		window.Content().Refresh()
 	}
 	
-	mainUI := ui.NewMainUI(&window, toggleTheme, isDarkMode, nil)
+	mainUI := ui.NewMainUI(a, &window, toggleTheme, isDarkMode, nil)
 	window.SetContent(mainUI.BuildUI())
 	
 	window.ShowAndRun()